@@ -9,12 +9,14 @@ impl From<&[u8; 3]> for Address {
     }
 }
 
-impl From<usize> for Address {
-    fn from(value: usize) -> Self {
+impl TryFrom<usize> for Address {
+    type Error = Trap;
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
         if value > 0xFFF {
-            panic!("Address {:?} is too large", value);
+            return Err(Trap::LoadOutOfBounds(value));
         }
-        Address(value as u16)
+        Ok(Address(value as u16))
     }
 }
 
@@ -77,9 +79,24 @@ enum OpCode {
     RegLoad(Var),             // F
 }
 
+/// A structured fault raised while fetching or executing an instruction.
+///
+/// A malformed ROM should leave the host process standing, so anything that
+/// would otherwise slice out of bounds or mismatch the stack surfaces here and
+/// halts the run loop cleanly instead of panicking.
+#[derive(Debug)]
+enum Trap {
+    LoadOutOfBounds(usize),
+    StackOverflow,
+    StackUnderflow,
+    InvalidOpcode([u8; 4]),
+}
+
 struct SystemState {
     memory: Memory,
     registers: [u8; 16],
+    stack: [usize; 16],
+    i: usize,
     pc: usize,
     sp: usize,
 }
@@ -99,8 +116,11 @@ impl Memory {
             .expect("Failed to load into memory");
     }
 
-    fn fetch_opcode(&self, address: Address) -> Option<OpCode> {
+    fn fetch_opcode(&self, address: Address) -> Result<OpCode, Trap> {
         let idx: usize = address.into();
+        if idx + 2 > self.data.len() {
+            return Err(Trap::LoadOutOfBounds(idx));
+        }
         let opc = &self.data[idx..idx + 2];
         let parts = [
             (opc[0] & 0xF0) >> 4,
@@ -109,45 +129,42 @@ impl Memory {
             opc[1] & 0x0F,
         ];
         return match parts {
-            [0x0, 0x0, 0xE, 0xE] => Some(OpCode::Ret()),
-            [0x0, 0x0, 0xE, 0x0] => Some(OpCode::Clear()),
-            [0x0, ref addr @ ..] => Some(OpCode::CallRoutine(addr.into())),
-            [0x1, ref addr @ ..] => Some(OpCode::Jmp(addr.into())),
-            [0x2, ref addr @ ..] => Some(OpCode::CallSubroutine(addr.into())),
-            [0x3, x, ref rest @ ..] => Some(OpCode::SkipConstEq(Var(x), rest.into())),
-            [0x4, x, ref rest @ ..] => Some(OpCode::SkipConstNe(Var(x), rest.into())),
-            [0x5, x, y, 0x0] => Some(OpCode::SkipEq(Var(x), Var(y))),
-            [0x6, x, ref rest @ ..] => Some(OpCode::SetConst(Var(x), rest.into())),
-            [0x7, x, ref rest @ ..] => Some(OpCode::AddConst(Var(x), rest.into())),
-            [0x8, x, y, 0x0] => Some(OpCode::Set(Var(x), Var(y))),
-            [0x8, x, y, 0x1] => Some(OpCode::BitOR(Var(x), Var(y))),
-            [0x8, x, y, 0x2] => Some(OpCode::BitAND(Var(x), Var(y))),
-            [0x8, x, y, 0x3] => Some(OpCode::BitXOR(Var(x), Var(y))),
-            [0x8, x, y, 0x4] => Some(OpCode::AddEqReg(Var(x), Var(y))),
-            [0x8, x, y, 0x5] => Some(OpCode::SubEqReg(Var(x), Var(y))),
-            [0x8, x, y, 0x6] => Some(OpCode::BitRShift(Var(x), Var(y))),
-            [0x8, x, y, 0x7] => Some(OpCode::SubReg(Var(x), Var(y))),
-            [0x8, x, y, 0xE] => Some(OpCode::BitLShift(Var(x), Var(y))),
-            [0x9, x, y, 0x0] => Some(OpCode::SkipNe(Var(x), Var(y))),
-            [0xA, ref addr @ ..] => Some(OpCode::IToAddr(addr.into())),
-            [0xB, ref addr @ ..] => Some(OpCode::SetPc(addr.into())),
-            [0xC, x, ref c8 @ ..] => Some(OpCode::Rand(Var(x), c8.into())),
-            [0xD, x, y, c] => Some(OpCode::Draw(Var(x), Var(y), Const4(c))),
-            [0xE, x, 0x9, 0xE] => Some(OpCode::KeyEq(Var(x))),
-            [0xE, x, 0xA, 0x1] => Some(OpCode::KeyNe(Var(x))),
-            [0xF, x, 0x0, 0x7] => Some(OpCode::GetTimer(Var(x))),
-            [0xF, x, 0x1, 0xA] => Some(OpCode::AwaitKey(Var(x))),
-            [0xF, x, 0x1, 0x5] => Some(OpCode::SetDelayTimer(Var(x))),
-            [0xF, x, 0x1, 0x8] => Some(OpCode::SetSoundTimer(Var(x))),
-            [0xF, x, 0x1, 0xE] => Some(OpCode::IAdd(Var(x))),
-            [0xF, x, 0x2, 0x9] => Some(OpCode::ISetSprite(Var(x))),
-            [0xF, x, 0x3, 0x3] => Some(OpCode::StoreBCD(Var(x))),
-            [0xF, x, 0x5, 0x5] => Some(OpCode::RegDump(Var(x))),
-            [0xF, x, 0x6, 0x5] => Some(OpCode::RegLoad(Var(x))),
-            _ => {
-                println!("Unkown opcode for {:x?}", parts);
-                None
-            }
+            [0x0, 0x0, 0xE, 0xE] => Ok(OpCode::Ret()),
+            [0x0, 0x0, 0xE, 0x0] => Ok(OpCode::Clear()),
+            [0x0, ref addr @ ..] => Ok(OpCode::CallRoutine(addr.into())),
+            [0x1, ref addr @ ..] => Ok(OpCode::Jmp(addr.into())),
+            [0x2, ref addr @ ..] => Ok(OpCode::CallSubroutine(addr.into())),
+            [0x3, x, ref rest @ ..] => Ok(OpCode::SkipConstEq(Var(x), rest.into())),
+            [0x4, x, ref rest @ ..] => Ok(OpCode::SkipConstNe(Var(x), rest.into())),
+            [0x5, x, y, 0x0] => Ok(OpCode::SkipEq(Var(x), Var(y))),
+            [0x6, x, ref rest @ ..] => Ok(OpCode::SetConst(Var(x), rest.into())),
+            [0x7, x, ref rest @ ..] => Ok(OpCode::AddConst(Var(x), rest.into())),
+            [0x8, x, y, 0x0] => Ok(OpCode::Set(Var(x), Var(y))),
+            [0x8, x, y, 0x1] => Ok(OpCode::BitOR(Var(x), Var(y))),
+            [0x8, x, y, 0x2] => Ok(OpCode::BitAND(Var(x), Var(y))),
+            [0x8, x, y, 0x3] => Ok(OpCode::BitXOR(Var(x), Var(y))),
+            [0x8, x, y, 0x4] => Ok(OpCode::AddEqReg(Var(x), Var(y))),
+            [0x8, x, y, 0x5] => Ok(OpCode::SubEqReg(Var(x), Var(y))),
+            [0x8, x, y, 0x6] => Ok(OpCode::BitRShift(Var(x), Var(y))),
+            [0x8, x, y, 0x7] => Ok(OpCode::SubReg(Var(x), Var(y))),
+            [0x8, x, y, 0xE] => Ok(OpCode::BitLShift(Var(x), Var(y))),
+            [0x9, x, y, 0x0] => Ok(OpCode::SkipNe(Var(x), Var(y))),
+            [0xA, ref addr @ ..] => Ok(OpCode::IToAddr(addr.into())),
+            [0xB, ref addr @ ..] => Ok(OpCode::SetPc(addr.into())),
+            [0xC, x, ref c8 @ ..] => Ok(OpCode::Rand(Var(x), c8.into())),
+            [0xD, x, y, c] => Ok(OpCode::Draw(Var(x), Var(y), Const4(c))),
+            [0xE, x, 0x9, 0xE] => Ok(OpCode::KeyEq(Var(x))),
+            [0xE, x, 0xA, 0x1] => Ok(OpCode::KeyNe(Var(x))),
+            [0xF, x, 0x0, 0x7] => Ok(OpCode::GetTimer(Var(x))),
+            [0xF, x, 0x1, 0xA] => Ok(OpCode::AwaitKey(Var(x))),
+            [0xF, x, 0x1, 0x5] => Ok(OpCode::SetDelayTimer(Var(x))),
+            [0xF, x, 0x1, 0x8] => Ok(OpCode::SetSoundTimer(Var(x))),
+            [0xF, x, 0x1, 0xE] => Ok(OpCode::IAdd(Var(x))),
+            [0xF, x, 0x2, 0x9] => Ok(OpCode::ISetSprite(Var(x))),
+            [0xF, x, 0x3, 0x3] => Ok(OpCode::StoreBCD(Var(x))),
+            [0xF, x, 0x5, 0x5] => Ok(OpCode::RegDump(Var(x))),
+            [0xF, x, 0x6, 0x5] => Ok(OpCode::RegLoad(Var(x))),
+            _ => Err(Trap::InvalidOpcode(parts)),
         };
     }
 }
@@ -157,18 +174,162 @@ impl SystemState {
         SystemState {
             memory: Memory::new(),
             registers: [0; 16],
+            stack: [0; 16],
+            i: 0,
             pc: 0x200,
             sp: 0,
         }
     }
+
+    /// Fetch the opcode at `pc`, advance past it and run it.
+    ///
+    /// `pc` moves on by two before dispatch so the control-flow opcodes (jumps,
+    /// calls, skips) operate relative to the *following* instruction, as the
+    /// CHIP-8 spec expects. Returns the first [`Trap`] raised while fetching or
+    /// executing.
+    fn step(&mut self) -> Result<(), Trap> {
+        let op = self.memory.fetch_opcode(Address::try_from(self.pc)?)?;
+        self.pc += 2;
+        self.execute(op)
+    }
+
+    /// Drive the interpreter until a [`Trap`] halts it.
+    fn run(&mut self) -> Result<(), Trap> {
+        loop {
+            self.step()?;
+        }
+    }
+
+    fn reg(&self, Var(x): &Var) -> u8 {
+        self.registers[*x as usize]
+    }
+
+    fn set_reg(&mut self, Var(x): &Var, value: u8) {
+        self.registers[*x as usize] = value;
+    }
+
+    fn set_flag(&mut self, set: bool) {
+        self.registers[0xF] = set as u8;
+    }
+
+    fn execute(&mut self, op: OpCode) -> Result<(), Trap> {
+        match op {
+            // 0NNN machine-code routines are not emulated on a hosted target.
+            OpCode::CallRoutine(_) | OpCode::Clear() => {}
+            OpCode::Ret() => {
+                self.sp = self.sp.checked_sub(1).ok_or(Trap::StackUnderflow)?;
+                self.pc = self.stack[self.sp];
+            }
+            OpCode::Jmp(addr) => self.pc = addr.into(),
+            OpCode::CallSubroutine(addr) => {
+                if self.sp >= self.stack.len() {
+                    return Err(Trap::StackOverflow);
+                }
+                self.stack[self.sp] = self.pc;
+                self.sp += 1;
+                self.pc = addr.into();
+            }
+            OpCode::SkipConstEq(x, c) => {
+                if self.reg(&x) == c.0 {
+                    self.pc += 2;
+                }
+            }
+            OpCode::SkipConstNe(x, c) => {
+                if self.reg(&x) != c.0 {
+                    self.pc += 2;
+                }
+            }
+            OpCode::SkipEq(x, y) => {
+                if self.reg(&x) == self.reg(&y) {
+                    self.pc += 2;
+                }
+            }
+            OpCode::SetConst(x, c) => self.set_reg(&x, c.0),
+            OpCode::AddConst(x, c) => self.set_reg(&x, self.reg(&x).wrapping_add(c.0)),
+            OpCode::Set(x, y) => self.set_reg(&x, self.reg(&y)),
+            OpCode::BitOR(x, y) => self.set_reg(&x, self.reg(&x) | self.reg(&y)),
+            OpCode::BitAND(x, y) => self.set_reg(&x, self.reg(&x) & self.reg(&y)),
+            OpCode::BitXOR(x, y) => self.set_reg(&x, self.reg(&x) ^ self.reg(&y)),
+            OpCode::AddEqReg(x, y) => {
+                let (result, carry) = self.reg(&x).overflowing_add(self.reg(&y));
+                self.set_reg(&x, result);
+                self.set_flag(carry);
+            }
+            OpCode::SubEqReg(x, y) => {
+                let (result, borrow) = self.reg(&x).overflowing_sub(self.reg(&y));
+                self.set_reg(&x, result);
+                self.set_flag(!borrow);
+            }
+            OpCode::BitRShift(x, _) => {
+                let value = self.reg(&x);
+                self.set_reg(&x, value >> 1);
+                self.set_flag(value & 0x1 == 1);
+            }
+            OpCode::SubReg(x, y) => {
+                let (result, borrow) = self.reg(&y).overflowing_sub(self.reg(&x));
+                self.set_reg(&x, result);
+                self.set_flag(!borrow);
+            }
+            OpCode::BitLShift(x, _) => {
+                let value = self.reg(&x);
+                self.set_reg(&x, value << 1);
+                self.set_flag(value & 0x80 != 0);
+            }
+            OpCode::SkipNe(x, y) => {
+                if self.reg(&x) != self.reg(&y) {
+                    self.pc += 2;
+                }
+            }
+            OpCode::IToAddr(addr) => self.i = addr.into(),
+            OpCode::SetPc(addr) => self.pc = usize::from(addr) + self.registers[0] as usize,
+            // Without an entropy source we yield a constant zero; a real build
+            // wires a PRNG in here and masks it with `c.0`.
+            OpCode::Rand(x, _) => self.set_reg(&x, 0),
+            // Drawing needs a framebuffer the hosted stub does not carry yet, so
+            // no pixels are touched and no collision is reported.
+            OpCode::Draw(_, _, _) => self.set_flag(false),
+            // Input and timers stay inert until a host front-end is attached.
+            OpCode::KeyEq(_)
+            | OpCode::KeyNe(_)
+            | OpCode::AwaitKey(_)
+            | OpCode::GetTimer(_)
+            | OpCode::SetDelayTimer(_)
+            | OpCode::SetSoundTimer(_) => {}
+            OpCode::IAdd(x) => self.i += self.reg(&x) as usize,
+            OpCode::ISetSprite(x) => self.i = self.reg(&x) as usize * 5,
+            OpCode::StoreBCD(x) => {
+                let value = self.reg(&x);
+                if self.i + 3 > self.memory.data.len() {
+                    return Err(Trap::LoadOutOfBounds(self.i));
+                }
+                self.memory.data[self.i] = value / 100;
+                self.memory.data[self.i + 1] = (value / 10) % 10;
+                self.memory.data[self.i + 2] = value % 10;
+            }
+            OpCode::RegDump(Var(x)) => {
+                let end = self.i + x as usize + 1;
+                if end > self.memory.data.len() {
+                    return Err(Trap::LoadOutOfBounds(end - 1));
+                }
+                self.memory.data[self.i..end].copy_from_slice(&self.registers[..=x as usize]);
+            }
+            OpCode::RegLoad(Var(x)) => {
+                let end = self.i + x as usize + 1;
+                if end > self.memory.data.len() {
+                    return Err(Trap::LoadOutOfBounds(end - 1));
+                }
+                self.registers[..=x as usize].copy_from_slice(&self.memory.data[self.i..end]);
+            }
+        }
+        Ok(())
+    }
 }
 
 fn main() {
     let mut state = SystemState::new();
     let test_path = Path::new("../chip8-test-rom/chip8-test-rom.ch8");
     state.memory.load_program(test_path);
-    println!(
-        "first opcode = {:x?}",
-        state.memory.fetch_opcode(state.pc.into())
-    );
+    if let Err(trap) = state.run() {
+        println!("halted on trap: {:x?}", trap);
+    }
 }